@@ -5,7 +5,24 @@
 //!
 //! Another use case for frontmatter is modifying the book's theme
 //! to place frontmatter variables in HTML.
+//!
+//! Note that this preprocessor doesn't need to care whether a chapter's
+//! frontmatter was originally written as a `+++ ... +++` TOML fence, a
+//! `--- ... ---` YAML fence, or a JSON object: by the time `Chapter` reaches
+//! us over stdin it has already been normalized into the typed
+//! `Frontmatter` struct by mdbook itself, `date` included, so
+//! `reformat_date` below behaves the same no matter which source format a
+//! book's authors used.
+//!
+//! Diagnostics are logged through the `log` crate rather than `println!`/
+//! `eprintln!`: stdout is reserved for the single
+//! `serde_json::to_writer(io::stdout(), ...)` call that ships the processed
+//! book back to mdbook, and any stray print there would corrupt that JSON
+//! stream. `CmdPreprocessor::init_logger` wires up a sensible env-driven
+//! default (stderr only, level controlled by `RUST_LOG`) so this example
+//! doesn't have to.
 use crate::all_caps_lib::FrontmatterPreprocessor;
+use log::error;
 use mdbook::book::Book;
 use mdbook::errors::Error;
 use mdbook::preprocess::{CmdPreprocessor, Preprocessor, PreprocessorContext};
@@ -15,9 +32,14 @@ use std::process;
 
 /// Main function for preprocessing data in frontmatter
 fn main() {
+    // a preprocessor's stdout *is* the JSON book stream back to mdbook, so
+    // diagnostics must only ever go to stderr; install mdbook's default
+    // env-driven logger rather than reaching for println!/eprintln!.
+    CmdPreprocessor::init_logger();
+
     // lightweight approach to  capture args from env
     let args: Vec<String> = std::env::args().collect();
-    let preprocessor = FrontmatterPreprocessor::default();
+    let preprocessor = FrontmatterPreprocessor;
 
     // mdbook make two preprocessing requests:
     // 1) check that the renderer is supported
@@ -28,7 +50,7 @@ fn main() {
     } else {
         // Normal operation - process book contents
         if let Err(e) = preprocessor.handle_preprocessing() {
-            eprintln!("Error processing frontmatter: {:?}", e);
+            error!("Error processing frontmatter: {:?}", e);
             std::process::exit(1);
         }
     }
@@ -49,10 +71,10 @@ fn handle_supports(pre: &dyn Preprocessor, renderer: &str) -> ! {
 /// in the preprocessor's `lib.rs` file.
 mod all_caps_lib {
     use super::*;
+    use log::{debug, warn};
     use mdbook::BookItem;
 
     /// A preprocessor for doing things with frontmatter.
-    #[derive(Default)]
     pub struct FrontmatterPreprocessor;
 
     impl FrontmatterPreprocessor {
@@ -66,9 +88,8 @@ mod all_caps_lib {
             let version_req = VersionReq::parse(mdbook::MDBOOK_VERSION)?;
 
             if !version_req.matches(&book_version) {
-                // log error
-                eprintln!(
-                    "Warning: The {} plugin was built against version {} of mdbook, \
+                warn!(
+                    "The {} plugin was built against version {} of mdbook, \
                      but we're being called from version {}",
                     self.name(),
                     mdbook::MDBOOK_VERSION,
@@ -78,7 +99,7 @@ mod all_caps_lib {
 
             // process and return book to stdout
             let processed_book = self.run(&ctx, book)?;
-            serde_json::to_writer(io::stdout(), &processed_book)?;
+            CmdPreprocessor::emit(io::stdout(), &processed_book)?;
             Ok(())
         }
 
@@ -105,22 +126,67 @@ mod all_caps_lib {
 
         fn run(&self, _ctx: &PreprocessorContext, mut book: Book) -> Result<Book, Error> {
             // loop through each book item to find chapters
+            //
+            // if the book is configured with `sort_by = "date"` or `"weight"`,
+            // siblings have already been reordered by the built-in sorting pass
+            // before preprocessors run, so `for_each_mut` here visits chapters in
+            // their final, renderer-facing order rather than SUMMARY.md order.
             book.for_each_mut(|item| {
                 if let BookItem::Chapter(chapter) = item {
-                    println!("before: {:?}", chapter.frontmatter);
-                    for (key, val) in chapter.frontmatter.iter_mut() {
-                        // ensure all uppercase
-                        *val = val.to_uppercase();
-
-                        // format date as another example
-                        if key == "date" {
-                            *val = self.reformat_date(val).expect(&format!(
+                    // draft chapters are excluded from the rendered output by the HTML
+                    // renderer, but they're still handed to preprocessors, so skip
+                    // touching them here rather than mutating hidden content.
+                    if chapter.frontmatter.draft {
+                        return;
+                    }
+
+                    // the tag/category index generator inserts its own virtual
+                    // chapters (tag listing pages, the "all tags" page) ahead of
+                    // rendering; leave their frontmatter alone so badges stay
+                    // readable instead of getting the same uppercase treatment
+                    // as hand-authored content.
+                    if matches!(
+                        chapter.frontmatter.template.as_deref(),
+                        Some("tag_index") | Some("category_index")
+                    ) {
+                        return;
+                    }
+
+                    debug!("before: {:?}", chapter.frontmatter);
+
+                    // well-known fields get typed access instead of string matching
+                    if let Some(title) = chapter.frontmatter.title.as_mut() {
+                        *title = title.to_uppercase();
+                    }
+                    if let Some(description) = chapter.frontmatter.description.as_mut() {
+                        *description = description.to_uppercase();
+                    }
+                    if let Some(slug) = chapter.frontmatter.slug.as_mut() {
+                        *slug = slug.to_uppercase();
+                    }
+                    if let Some(category) = chapter.frontmatter.category.as_mut() {
+                        *category = category.to_uppercase();
+                    }
+                    for tag in chapter.frontmatter.tags.iter_mut() {
+                        *tag = tag.to_uppercase();
+                    }
+                    if let Some(date) = chapter.frontmatter.date.as_mut() {
+                        *date = self.reformat_date(date).unwrap_or_else(|_| {
+                            panic!(
                                 "date format incorrect. expected YYYY-MM-DD, received {}",
-                                val
-                            ));
+                                date
+                            )
+                        });
+                    }
+
+                    // everything else (unknown keys) still falls out of the `extra` map
+                    for val in chapter.frontmatter.extra.values_mut() {
+                        if let serde_json::Value::String(s) = val {
+                            *s = s.to_uppercase();
                         }
                     }
-                    println!("after: {:?}", chapter.frontmatter);
+
+                    debug!("after: {:?}", chapter.frontmatter);
                 }
             });
 
@@ -135,7 +201,6 @@ mod all_caps_lib {
     #[cfg(test)]
     mod test {
         use super::*;
-        use mdbook::book::Chapter;
 
         #[test]
         fn frontmatter_preprocessor_run() {
@@ -181,7 +246,7 @@ mod all_caps_lib {
             let input_json = input_json.as_bytes();
 
             let (ctx, book) = mdbook::preprocess::CmdPreprocessor::parse_input(input_json).unwrap();
-            let result = FrontmatterPreprocessor::default().run(&ctx, book);
+            let result = FrontmatterPreprocessor.run(&ctx, book);
             let processed_book = result.expect("book processed");
 
             // only one section - chapter with frontmatter
@@ -191,8 +256,11 @@ mod all_caps_lib {
             let processed_frontmatter = &chapter_1.frontmatter;
             let expected_date = "08-02-2024";
             let expected_author = "GRANT (@GRANTKEE)";
-            assert_eq!(processed_frontmatter["author"], expected_author);
-            assert_eq!(processed_frontmatter["date"], expected_date);
+            assert_eq!(processed_frontmatter.date.as_deref(), Some(expected_date));
+            assert_eq!(
+                processed_frontmatter.extra["author"],
+                serde_json::Value::String(expected_author.to_string())
+            );
         }
     }
 }
@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+
+use crate::book::tags::{escape_markdown_link_text, slugify};
+use crate::book::{Book, BookItem, Chapter};
+
+/// One chapter that belongs to a given category, as collected by
+/// [`collect_categories`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryEntry {
+    pub chapter_name: String,
+    pub path: String,
+}
+
+/// Collect every chapter's frontmatter `category` into a
+/// `category -> chapters` map, sorted alphabetically by category.
+///
+/// Mirrors [`crate::book::collect_tags`], but groups by the single
+/// `category` field instead of the `tags` list.
+pub fn collect_categories(book: &Book) -> BTreeMap<String, Vec<CategoryEntry>> {
+    let mut categories: BTreeMap<String, Vec<CategoryEntry>> = BTreeMap::new();
+    book.for_each(|item| {
+        if let BookItem::Chapter(chapter) = item {
+            if chapter.frontmatter.template.as_deref() == Some("category_index") {
+                return;
+            }
+            let Some(path) = chapter.path.as_ref() else {
+                return;
+            };
+            let Some(category) = chapter.frontmatter.category.as_ref() else {
+                return;
+            };
+            categories
+                .entry(category.clone())
+                .or_default()
+                .push(CategoryEntry {
+                    chapter_name: chapter.name.clone(),
+                    path: path.display().to_string(),
+                });
+        }
+    });
+    categories
+}
+
+/// Append one virtual chapter per category, plus an "All Categories" index,
+/// to `book`. Mirrors [`crate::book::insert_tag_index_chapters`].
+///
+/// Each generated chapter has `frontmatter.template` set to
+/// `"category_index"` so other passes (and the theme) can tell it apart from
+/// hand-authored content. Does nothing if no chapter in the book has a
+/// category.
+pub fn insert_category_index_chapters(book: &mut Book) {
+    let categories = collect_categories(book);
+    if categories.is_empty() {
+        return;
+    }
+
+    let mut all_categories_body = String::from("# Categories\n\n");
+    for (category, entries) in &categories {
+        let slug = slugify(category);
+        let category_text = escape_markdown_link_text(category);
+        all_categories_body.push_str(&format!(
+            "- [{category_text}](categories/{slug}.md) ({})\n",
+            entries.len()
+        ));
+
+        let mut body = format!("# Category: {category_text}\n\n");
+        for entry in entries {
+            body.push_str(&format!(
+                "- [{}]({})\n",
+                escape_markdown_link_text(&entry.chapter_name),
+                entry.path
+            ));
+        }
+        book.sections.push(BookItem::Chapter(Box::new(
+            category_index_chapter(format!("Category: {category}"), body),
+        )));
+    }
+
+    book.sections.push(BookItem::Chapter(Box::new(
+        category_index_chapter("All Categories".to_string(), all_categories_body),
+    )));
+}
+
+fn category_index_chapter(name: String, content: String) -> Chapter {
+    let mut chapter = Chapter::new(name, content);
+    chapter.frontmatter.template = Some("category_index".to_string());
+    chapter
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn chapter_with_category(name: &str, path: &str, category: Option<&str>) -> BookItem {
+        let mut chapter = Chapter::new(name, "");
+        chapter.path = Some(PathBuf::from(path));
+        chapter.frontmatter.category = category.map(str::to_string);
+        BookItem::Chapter(Box::new(chapter))
+    }
+
+    #[test]
+    fn collects_categories_across_chapters() {
+        let mut book = Book::new();
+        book.sections = vec![
+            chapter_with_category("one", "one.md", Some("guides")),
+            chapter_with_category("two", "two.md", Some("guides")),
+            chapter_with_category("three", "three.md", Some("reference")),
+        ];
+
+        let categories = collect_categories(&book);
+        assert_eq!(categories.len(), 2);
+        assert_eq!(categories["guides"].len(), 2);
+        assert_eq!(categories["reference"][0].chapter_name, "three");
+    }
+
+    #[test]
+    fn inserts_one_page_per_category_plus_all_categories() {
+        let mut book = Book::new();
+        book.sections = vec![
+            chapter_with_category("one", "one.md", Some("guides")),
+            chapter_with_category("two", "two.md", Some("reference")),
+        ];
+
+        insert_category_index_chapters(&mut book);
+
+        let generated: Vec<&str> = book
+            .sections
+            .iter()
+            .filter_map(|item| match item {
+                BookItem::Chapter(c) if c.frontmatter.template.as_deref() == Some("category_index") => {
+                    Some(c.name.as_str())
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            generated,
+            vec!["Category: guides", "Category: reference", "All Categories"]
+        );
+    }
+
+    #[test]
+    fn does_nothing_when_no_categories_present() {
+        let mut book = Book::new();
+        book.sections = vec![chapter_with_category("one", "one.md", None)];
+
+        insert_category_index_chapters(&mut book);
+
+        assert_eq!(book.sections.len(), 1);
+    }
+}
@@ -0,0 +1,247 @@
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::book::{BookItem, SectionNumber};
+use crate::errors::{Context, Error};
+
+/// A single piece of content in a book.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Chapter {
+    pub name: String,
+    pub content: String,
+    #[serde(default)]
+    pub number: Option<SectionNumber>,
+    #[serde(default)]
+    pub sub_items: Vec<BookItem>,
+    pub path: Option<PathBuf>,
+    pub source_path: Option<PathBuf>,
+    #[serde(default)]
+    pub parent_names: Vec<String>,
+    #[serde(default)]
+    pub frontmatter: Frontmatter,
+}
+
+impl Chapter {
+    pub fn new(name: impl Into<String>, content: impl Into<String>) -> Self {
+        Chapter {
+            name: name.into(),
+            content: content.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// The typed schema for a chapter's frontmatter block. Well-known fields get
+/// real types instead of forcing every preprocessor to hand-parse a
+/// stringly-typed map; anything mdbook doesn't recognize still round-trips
+/// through `extra`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Frontmatter {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub date: Option<String>,
+    #[serde(default)]
+    pub slug: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Mirrors Zola's draft front matter: `true` hides the chapter from
+    /// rendered output while still handing it to preprocessors.
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub template: Option<String>,
+    /// Catch-all for keys this schema doesn't know about yet.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Frontmatter {
+    /// Parse a chapter's raw source text into its frontmatter (if any) and
+    /// the remaining markdown body.
+    ///
+    /// Detects the frontmatter format from its opening fence: `+++ ... +++`
+    /// is parsed as TOML, `--- ... ---` as YAML, and `{ ... }` / `;;; ... ;;;`
+    /// as JSON. Chapters with no recognized fence simply have no
+    /// frontmatter.
+    pub fn parse(raw: &str) -> Result<(Self, &str), Error> {
+        let raw = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+
+        let (format, text, body) = if let Some(rest) = raw.strip_prefix("+++") {
+            let (text, body) = split_fence(rest, "+++")?;
+            (FenceFormat::Toml, text, body)
+        } else if let Some(rest) = raw.strip_prefix("---") {
+            let (text, body) = split_fence(rest, "---")?;
+            (FenceFormat::Yaml, text, body)
+        } else if let Some(rest) = raw.strip_prefix(";;;") {
+            let (text, body) = split_fence(rest, ";;;")?;
+            (FenceFormat::Json, text, body)
+        } else if raw.trim_start().starts_with('{') {
+            let (text, body) = split_json_object(raw);
+            (FenceFormat::Json, text, body)
+        } else {
+            return Ok((Frontmatter::default(), raw));
+        };
+
+        let mut frontmatter: Frontmatter = match format {
+            FenceFormat::Toml => toml::from_str(text).context("invalid TOML frontmatter")?,
+            FenceFormat::Yaml => serde_yaml::from_str(text).context("invalid YAML frontmatter")?,
+            FenceFormat::Json => serde_json::from_str(text).context("invalid JSON frontmatter")?,
+        };
+
+        if let Some(date) = frontmatter.date.take() {
+            frontmatter.date = Some(validate_date(&date)?);
+        }
+
+        Ok((frontmatter, body))
+    }
+}
+
+/// Which deserializer a frontmatter fence selects.
+enum FenceFormat {
+    Toml,
+    Yaml,
+    Json,
+}
+
+/// Split `rest` (the text after an opening fence) on the matching closing
+/// `fence`, returning the text in between and everything after it.
+///
+/// A missing closing fence is an error rather than "no frontmatter": both
+/// TOML and YAML treat `#` as a comment character, so a chapter body that
+/// opens with a `# Heading` would otherwise be silently swallowed whole as
+/// unparsed frontmatter text, leaving the chapter's rendered body empty.
+fn split_fence<'a>(rest: &'a str, fence: &str) -> Result<(&'a str, &'a str), Error> {
+    match rest.find(fence) {
+        Some(end) => {
+            let text = &rest[..end];
+            let body = &rest[end + fence.len()..];
+            Ok((text.trim_start_matches('\n'), body.trim_start_matches('\n')))
+        }
+        None => anyhow::bail!("unterminated frontmatter fence, expected closing `{fence}`"),
+    }
+}
+
+/// JSON frontmatter has no closing fence of its own; the object's closing
+/// `}` (tracked via brace depth, so nested objects don't end things early)
+/// marks the end of the frontmatter.
+fn split_json_object(raw: &str) -> (&str, &str) {
+    let mut depth = 0usize;
+    for (idx, ch) in raw.char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    let end = idx + ch.len_utf8();
+                    return (&raw[..end], raw[end..].trim_start_matches('\n'));
+                }
+            }
+            _ => {}
+        }
+    }
+    (raw, "")
+}
+
+/// Confirm `date` is a well-formed, calendar-valid `YYYY-MM-DD` string.
+///
+/// YAML naturally parses a bare `date: 2024-08-02` into a typed date, but
+/// TOML and JSON hand it back as a plain string, so every format is
+/// normalized through this validator to keep downstream date handling
+/// (sorting, display) format-agnostic. Delegates to the same
+/// `NaiveDate::parse_from_str` that `book::sort` uses, so a date that passes
+/// here is guaranteed to also succeed as a sort key.
+fn validate_date(date: &str) -> Result<String, Error> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map(|_| date.to_string())
+        .map_err(|_| anyhow::anyhow!("invalid date `{date}`, expected YYYY-MM-DD"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_keys_land_in_extra() {
+        let fm: Frontmatter =
+            serde_json::from_str(r#"{"author": "grant", "date": "2024-08-02"}"#).unwrap();
+        assert_eq!(fm.date.as_deref(), Some("2024-08-02"));
+        assert_eq!(
+            fm.extra.get("author"),
+            Some(&Value::String("grant".to_string()))
+        );
+    }
+
+    #[test]
+    fn draft_defaults_to_false() {
+        let fm: Frontmatter = serde_json::from_str(r#"{"title": "Hello"}"#).unwrap();
+        assert!(!fm.draft);
+    }
+
+    #[test]
+    fn parses_toml_frontmatter() {
+        let raw = "+++\ntitle = \"Hello\"\ndate = \"2024-08-02\"\n+++\n# Hello\n";
+        let (fm, body) = Frontmatter::parse(raw).unwrap();
+        assert_eq!(fm.title.as_deref(), Some("Hello"));
+        assert_eq!(fm.date.as_deref(), Some("2024-08-02"));
+        assert_eq!(body, "# Hello\n");
+    }
+
+    #[test]
+    fn parses_yaml_frontmatter() {
+        let raw = "---\ntitle: Hello\ndate: \"2024-08-02\"\ntags:\n  - rust\n---\n# Hello\n";
+        let (fm, body) = Frontmatter::parse(raw).unwrap();
+        assert_eq!(fm.title.as_deref(), Some("Hello"));
+        assert_eq!(fm.date.as_deref(), Some("2024-08-02"));
+        assert_eq!(fm.tags, vec!["rust".to_string()]);
+        assert_eq!(body, "# Hello\n");
+    }
+
+    #[test]
+    fn parses_json_frontmatter() {
+        let raw = "{\"title\": \"Hello\", \"draft\": true}\n# Hello\n";
+        let (fm, body) = Frontmatter::parse(raw).unwrap();
+        assert_eq!(fm.title.as_deref(), Some("Hello"));
+        assert!(fm.draft);
+        assert_eq!(body, "# Hello\n");
+    }
+
+    #[test]
+    fn chapters_without_frontmatter_are_untouched() {
+        let raw = "# Hello\nno frontmatter here\n";
+        let (fm, body) = Frontmatter::parse(raw).unwrap();
+        assert_eq!(fm, Frontmatter::default());
+        assert_eq!(body, raw);
+    }
+
+    #[test]
+    fn rejects_malformed_dates_from_any_format() {
+        let raw = "+++\ndate = \"08/02/2024\"\n+++\nbody\n";
+        assert!(Frontmatter::parse(raw).is_err());
+    }
+
+    #[test]
+    fn rejects_dates_that_are_not_real_calendar_days() {
+        let raw = "+++\ndate = \"2024-13-99\"\n+++\nbody\n";
+        assert!(Frontmatter::parse(raw).is_err());
+    }
+
+    #[test]
+    fn rejects_unterminated_toml_fence_instead_of_eating_the_body() {
+        let raw = "+++\ntitle = \"Hello\"\n# Body without closing fence\n";
+        assert!(Frontmatter::parse(raw).is_err());
+    }
+
+    #[test]
+    fn rejects_unterminated_yaml_fence_instead_of_eating_the_body() {
+        let raw = "---\ntitle: Hello\n# A heading that looks like a YAML comment\n";
+        assert!(Frontmatter::parse(raw).is_err());
+    }
+}
@@ -0,0 +1,83 @@
+//! The in-memory representation of a book that preprocessors and renderers
+//! operate on.
+
+mod categories;
+mod chapter;
+mod sort;
+mod tags;
+
+pub use self::categories::{collect_categories, insert_category_index_chapters, CategoryEntry};
+pub use self::chapter::{Chapter, Frontmatter};
+pub use self::sort::SortBy;
+pub use self::tags::{collect_tags, insert_tag_index_chapters, TagEntry};
+
+use serde::{Deserialize, Serialize};
+
+/// A section number, e.g. `1.2.3`, identifying a chapter's place in the book.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SectionNumber(pub Vec<u32>);
+
+/// An item in a [`Book`]. Chapters form a tree via their `sub_items`;
+/// `Separator` and `PartTitle` mirror the non-chapter entries SUMMARY.md can
+/// contain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum BookItem {
+    Chapter(Box<Chapter>),
+    Separator,
+    PartTitle(String),
+}
+
+/// A book, as handed to preprocessors and renderers: a flat list of
+/// top-level [`BookItem`]s, each of which may itself contain nested items.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Book {
+    pub sections: Vec<BookItem>,
+    /// Prevents direct construction of a `Book` outside this crate, the way
+    /// upstream mdbook does, while still round-tripping through the wire
+    /// format preprocessors receive it in.
+    #[serde(rename = "__non_exhaustive", default, skip_serializing)]
+    __non_exhaustive: Option<()>,
+}
+
+impl Book {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `func` against every [`BookItem`] in the book, recursing into
+    /// nested `sub_items` depth-first. Preprocessors use this to reach every
+    /// chapter regardless of how deeply it's nested.
+    pub fn for_each_mut<F>(&mut self, mut func: F)
+    where
+        F: FnMut(&mut BookItem),
+    {
+        for_each_mut_helper(&mut self.sections, &mut func);
+    }
+
+    /// Like [`Book::for_each_mut`], but read-only.
+    pub fn for_each<'a, F>(&'a self, mut func: F)
+    where
+        F: FnMut(&'a BookItem),
+    {
+        for_each_helper(&self.sections, &mut func);
+    }
+}
+
+fn for_each_mut_helper(items: &mut [BookItem], func: &mut impl FnMut(&mut BookItem)) {
+    for item in items {
+        if let BookItem::Chapter(chapter) = item {
+            for_each_mut_helper(&mut chapter.sub_items, func);
+        }
+        func(item);
+    }
+}
+
+fn for_each_helper<'a>(items: &'a [BookItem], func: &mut impl FnMut(&'a BookItem)) {
+    for item in items {
+        if let BookItem::Chapter(chapter) = item {
+            for_each_helper(&chapter.sub_items, func);
+        }
+        func(item);
+    }
+}
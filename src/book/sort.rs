@@ -0,0 +1,156 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::book::{Book, BookItem};
+
+/// How chapters should be reordered within their section, mirroring the
+/// `sort_by` front matter Zola supports at the section level.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortBy {
+    /// Newest-first, by the frontmatter `date`.
+    Date,
+    /// Ascending, by the frontmatter `weight` (or `order`) key.
+    Weight,
+    /// Leave chapters in SUMMARY.md declaration order (the default).
+    #[default]
+    None,
+}
+
+impl Book {
+    /// Reorder every sibling list in the book according to `sort_by`.
+    ///
+    /// Chapters lacking the relevant sort key are left in their original
+    /// SUMMARY.md order at the end of their section, rather than being
+    /// treated as equal to chapters that do have one.
+    pub fn sort_by(&mut self, sort_by: SortBy) {
+        if sort_by == SortBy::None {
+            return;
+        }
+        sort_siblings(&mut self.sections, sort_by);
+    }
+}
+
+fn sort_siblings(items: &mut [BookItem], sort_by: SortBy) {
+    // stable, so items with no sort key (or tied keys) keep their original
+    // SUMMARY.md order relative to each other.
+    items.sort_by(|a, b| match (sort_key(a, sort_by), sort_key(b, sort_by)) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+
+    for item in items.iter_mut() {
+        if let BookItem::Chapter(chapter) = item {
+            sort_siblings(&mut chapter.sub_items, sort_by);
+        }
+    }
+}
+
+/// The effective sort key for an item, or `None` if it has no relevant
+/// frontmatter key (such items sort after everything else, stably).
+fn sort_key(item: &BookItem, sort_by: SortBy) -> Option<SortKey> {
+    let BookItem::Chapter(chapter) = item else {
+        return None;
+    };
+
+    match sort_by {
+        SortBy::Date => {
+            let date = chapter.frontmatter.date.as_deref()?;
+            parse_date(date).map(|date| SortKey::Date(std::cmp::Reverse(date)))
+        }
+        SortBy::Weight => {
+            let weight = chapter
+                .frontmatter
+                .extra
+                .get("weight")
+                .or_else(|| chapter.frontmatter.extra.get("order"))?;
+            weight.as_i64().map(SortKey::Weight)
+        }
+        SortBy::None => None,
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum SortKey {
+    // Wrapped so newest dates (the larger value) sort first via `Reverse`.
+    Date(std::cmp::Reverse<NaiveDate>),
+    Weight(i64),
+}
+
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .or_else(|_| chrono::DateTime::parse_from_rfc3339(date).map(|dt| dt.date_naive()))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::book::Chapter;
+    use serde_json::Value;
+
+    fn chapter_with_date(name: &str, date: Option<&str>) -> BookItem {
+        let mut chapter = Chapter::new(name, "");
+        chapter.frontmatter.date = date.map(str::to_string);
+        BookItem::Chapter(Box::new(chapter))
+    }
+
+    fn chapter_with_weight(name: &str, weight: Option<i64>) -> BookItem {
+        let mut chapter = Chapter::new(name, "");
+        if let Some(weight) = weight {
+            chapter
+                .frontmatter
+                .extra
+                .insert("weight".to_string(), Value::from(weight));
+        }
+        BookItem::Chapter(Box::new(chapter))
+    }
+
+    fn names(book: &Book) -> Vec<&str> {
+        book.sections
+            .iter()
+            .map(|item| match item {
+                BookItem::Chapter(c) => c.name.as_str(),
+                _ => "",
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sorts_newest_date_first() {
+        let mut book = Book::new();
+        book.sections = vec![
+            chapter_with_date("old", Some("2020-01-01")),
+            chapter_with_date("new", Some("2024-01-01")),
+            chapter_with_date("mid", Some("2022-01-01")),
+        ];
+        book.sort_by(SortBy::Date);
+        assert_eq!(names(&book), vec!["new", "mid", "old"]);
+    }
+
+    #[test]
+    fn undated_chapters_stay_last_in_declared_order() {
+        let mut book = Book::new();
+        book.sections = vec![
+            chapter_with_date("undated-a", None),
+            chapter_with_date("dated", Some("2024-01-01")),
+            chapter_with_date("undated-b", None),
+        ];
+        book.sort_by(SortBy::Date);
+        assert_eq!(names(&book), vec!["dated", "undated-a", "undated-b"]);
+    }
+
+    #[test]
+    fn sorts_ascending_by_weight() {
+        let mut book = Book::new();
+        book.sections = vec![
+            chapter_with_weight("third", Some(3)),
+            chapter_with_weight("first", Some(1)),
+            chapter_with_weight("second", Some(2)),
+        ];
+        book.sort_by(SortBy::Weight);
+        assert_eq!(names(&book), vec!["first", "second", "third"]);
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::BTreeMap;
+
+use crate::book::{Book, BookItem, Chapter};
+
+/// One chapter that carries a given tag, as collected by [`collect_tags`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagEntry {
+    pub chapter_name: String,
+    pub path: String,
+}
+
+/// Collect every frontmatter `tags` entry across the book into a
+/// `tag -> chapters` map, sorted alphabetically by tag.
+///
+/// Chapters without a `path` (such as the virtual chapters
+/// [`insert_tag_index_chapters`] itself generates) are skipped, so rerunning
+/// tag collection after inserting the index pages doesn't fold the index
+/// pages back into their own listings.
+pub fn collect_tags(book: &Book) -> BTreeMap<String, Vec<TagEntry>> {
+    let mut tags: BTreeMap<String, Vec<TagEntry>> = BTreeMap::new();
+    book.for_each(|item| {
+        if let BookItem::Chapter(chapter) = item {
+            if chapter.frontmatter.template.as_deref() == Some("tag_index") {
+                return;
+            }
+            let Some(path) = chapter.path.as_ref() else {
+                return;
+            };
+            for tag in &chapter.frontmatter.tags {
+                tags.entry(tag.clone()).or_default().push(TagEntry {
+                    chapter_name: chapter.name.clone(),
+                    path: path.display().to_string(),
+                });
+            }
+        }
+    });
+    tags
+}
+
+/// Append one virtual chapter per tag, plus an "All Tags" index, to `book`.
+///
+/// Each generated chapter has `frontmatter.template` set to `"tag_index"` so
+/// other passes (and the theme) can tell it apart from hand-authored
+/// content. Does nothing if no chapter in the book has any tags.
+pub fn insert_tag_index_chapters(book: &mut Book) {
+    let tags = collect_tags(book);
+    if tags.is_empty() {
+        return;
+    }
+
+    let mut all_tags_body = String::from("# Tags\n\n");
+    for (tag, entries) in &tags {
+        // the tag is user-controlled frontmatter content, so it's slugified
+        // for the path component (a raw "rust/cli" would otherwise nest into
+        // an unintended subdirectory) and escaped for the link text (a raw
+        // "a]b" would otherwise break the markdown link syntax).
+        let slug = slugify(tag);
+        let tag_text = escape_markdown_link_text(tag);
+        all_tags_body.push_str(&format!(
+            "- [{tag_text}](tags/{slug}.md) ({})\n",
+            entries.len()
+        ));
+
+        let mut body = format!("# Tag: {tag_text}\n\n");
+        for entry in entries {
+            body.push_str(&format!(
+                "- [{}]({})\n",
+                escape_markdown_link_text(&entry.chapter_name),
+                entry.path
+            ));
+        }
+        book.sections.push(BookItem::Chapter(Box::new(
+            tag_index_chapter(format!("Tag: {tag}"), body),
+        )));
+    }
+
+    book.sections.push(BookItem::Chapter(Box::new(tag_index_chapter(
+        "All Tags".to_string(),
+        all_tags_body,
+    ))));
+}
+
+fn tag_index_chapter(name: String, content: String) -> Chapter {
+    let mut chapter = Chapter::new(name, content);
+    chapter.frontmatter.template = Some("tag_index".to_string());
+    chapter
+}
+
+/// Turn arbitrary frontmatter text into a safe path component: lowercase
+/// ASCII alphanumerics separated by single dashes, with everything else
+/// (including `/`, which would otherwise nest into an unintended
+/// subdirectory) dropped.
+pub(crate) fn slugify(raw: &str) -> String {
+    let mut slug = String::with_capacity(raw.len());
+    let mut last_was_dash = false;
+    for ch in raw.chars() {
+        if ch.is_ascii_alphanumeric() {
+            slug.push(ch.to_ascii_lowercase());
+            last_was_dash = false;
+        } else if !last_was_dash && !slug.is_empty() {
+            slug.push('-');
+            last_was_dash = true;
+        }
+    }
+    while slug.ends_with('-') {
+        slug.pop();
+    }
+    slug
+}
+
+/// Escape the characters that would otherwise break `[text](target)`
+/// markdown link syntax if `raw` were spliced into `text` unescaped.
+pub(crate) fn escape_markdown_link_text(raw: &str) -> String {
+    raw.replace('\\', "\\\\")
+        .replace('[', "\\[")
+        .replace(']', "\\]")
+        .replace('(', "\\(")
+        .replace(')', "\\)")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn chapter_with_tags(name: &str, path: &str, tags: &[&str]) -> BookItem {
+        let mut chapter = Chapter::new(name, "");
+        chapter.path = Some(PathBuf::from(path));
+        chapter.frontmatter.tags = tags.iter().map(|t| t.to_string()).collect();
+        BookItem::Chapter(Box::new(chapter))
+    }
+
+    #[test]
+    fn collects_tags_across_chapters() {
+        let mut book = Book::new();
+        book.sections = vec![
+            chapter_with_tags("one", "one.md", &["rust", "cli"]),
+            chapter_with_tags("two", "two.md", &["rust"]),
+        ];
+
+        let tags = collect_tags(&book);
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags["rust"].len(), 2);
+        assert_eq!(tags["cli"].len(), 1);
+        assert_eq!(tags["cli"][0].chapter_name, "one");
+    }
+
+    #[test]
+    fn inserts_one_page_per_tag_plus_all_tags() {
+        let mut book = Book::new();
+        book.sections = vec![chapter_with_tags("one", "one.md", &["rust", "cli"])];
+
+        insert_tag_index_chapters(&mut book);
+
+        let generated: Vec<&str> = book
+            .sections
+            .iter()
+            .filter_map(|item| match item {
+                BookItem::Chapter(c) if c.frontmatter.template.as_deref() == Some("tag_index") => {
+                    Some(c.name.as_str())
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(generated, vec!["Tag: cli", "Tag: rust", "All Tags"]);
+    }
+
+    #[test]
+    fn does_nothing_when_no_tags_present() {
+        let mut book = Book::new();
+        book.sections = vec![chapter_with_tags("one", "one.md", &[])];
+
+        insert_tag_index_chapters(&mut book);
+
+        assert_eq!(book.sections.len(), 1);
+    }
+
+    #[test]
+    fn slugifies_tags_containing_path_separators() {
+        assert_eq!(slugify("rust/cli"), "rust-cli");
+    }
+
+    #[test]
+    fn slugifies_tags_containing_markdown_link_syntax() {
+        assert_eq!(slugify("a]b"), "a-b");
+    }
+
+    #[test]
+    fn tag_index_links_use_a_slug_path_and_escaped_text() {
+        let mut book = Book::new();
+        book.sections = vec![chapter_with_tags("one", "one.md", &["a]b", "rust/cli"])];
+
+        insert_tag_index_chapters(&mut book);
+
+        let all_tags = book
+            .sections
+            .iter()
+            .find_map(|item| match item {
+                BookItem::Chapter(c) if c.name == "All Tags" => Some(c.content.as_str()),
+                _ => None,
+            })
+            .unwrap();
+
+        assert!(all_tags.contains("[a\\]b](tags/a-b.md)"));
+        assert!(all_tags.contains("[rust/cli](tags/rust-cli.md)"));
+    }
+}
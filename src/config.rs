@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::book::SortBy;
+
+/// The `[book]` table of `book.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BookConfig {
+    #[serde(default)]
+    pub authors: Vec<String>,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub multilingual: bool,
+    #[serde(default = "default_src")]
+    pub src: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Reorder chapters by their frontmatter `date` or `weight` before
+    /// rendering. Defaults to [`SortBy::None`], which keeps SUMMARY.md order.
+    #[serde(default)]
+    pub sort_by: SortBy,
+}
+
+fn default_src() -> String {
+    "src".to_string()
+}
+
+/// The full `book.toml` configuration. Only the `[book]` table is typed;
+/// `[preprocessor.*]`, `[output.*]`, and anything else a plugin defines for
+/// itself is preserved untouched in `extra`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub book: BookConfig,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
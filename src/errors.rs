@@ -0,0 +1,8 @@
+//! mdbook's error type.
+//!
+//! Every fallible public API in this crate returns this [`Error`], which is
+//! a thin re-export of [`anyhow::Error`] so callers (and preprocessors built
+//! against this crate) can use `?` from `toml`, `serde_json`, `serde_yaml`,
+//! `semver`, or plain `io::Error` without any glue code.
+
+pub use anyhow::{Context, Error};
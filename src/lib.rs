@@ -0,0 +1,17 @@
+//! A small, self-contained subset of mdbook's book model: the typed
+//! [`Book`]/[`BookItem`]/[`Chapter`] tree and the preprocessor protocol used
+//! by external preprocessors like the one in
+//! `examples/frontmatter-feature.rs`.
+
+pub mod book;
+pub mod config;
+pub mod errors;
+pub mod preprocess;
+pub mod renderer;
+
+pub use book::{Book, BookItem};
+pub use config::Config;
+
+/// The version of mdbook this crate implements, used by preprocessors to
+/// check compatibility against the `mdbook_version` they're invoked with.
+pub const MDBOOK_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -0,0 +1,118 @@
+//! Support for writing external preprocessors: programs that mdbook invokes
+//! before rendering to transform a [`Book`] in place.
+
+use std::io::{Read, Write};
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::book::Book;
+use crate::config::Config;
+use crate::errors::{Context, Error};
+
+/// Information about the environment a preprocessor is being run in, handed
+/// to [`Preprocessor::run`] alongside the [`Book`] itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreprocessorContext {
+    pub root: PathBuf,
+    pub config: Config,
+    pub renderer: String,
+    pub mdbook_version: String,
+}
+
+/// Something that can transform a book before it's rendered.
+pub trait Preprocessor {
+    /// The preprocessor's name, used in `book.toml`'s `[preprocessor.*]`
+    /// tables and in the `supports` handshake.
+    fn name(&self) -> &str;
+
+    /// Transform `book`, returning the updated version.
+    fn run(&self, ctx: &PreprocessorContext, book: Book) -> Result<Book, Error>;
+
+    /// Whether this preprocessor should run for the given renderer.
+    /// Defaults to supporting everything.
+    fn supports_renderer(&self, renderer: &str) -> bool {
+        let _ = renderer;
+        true
+    }
+}
+
+/// Helpers for preprocessors run as a standalone command (mdbook's
+/// preprocessor protocol: `supports <renderer>` on argv, the `(context,
+/// book)` pair as JSON on stdin, the processed book as JSON on stdout).
+pub struct CmdPreprocessor;
+
+impl CmdPreprocessor {
+    /// Parse the `(PreprocessorContext, Book)` pair mdbook sends a
+    /// preprocessor on stdin.
+    pub fn parse_input<R: Read>(reader: R) -> Result<(PreprocessorContext, Book), Error> {
+        serde_json::from_reader(reader).context("unable to parse preprocessor input")
+    }
+
+    /// Write the processed `book` back to mdbook as JSON.
+    ///
+    /// This is the only thing a preprocessor binary should ever write to
+    /// `writer` (typically [`io::stdout`](std::io::stdout)): mdbook reads
+    /// exactly one JSON value back from it, so mixing in any other output
+    /// (a stray `println!`, a partial write) corrupts the stream. Use the
+    /// `log` crate via [`CmdPreprocessor::init_logger`] for diagnostics
+    /// instead.
+    pub fn emit<W: Write>(writer: W, book: &Book) -> Result<(), Error> {
+        serde_json::to_writer(writer, book).context("unable to write processed book")
+    }
+
+    /// Install a sensible default logger for a preprocessor binary: level
+    /// controlled by `RUST_LOG` (falling back to `warn`), written to stderr
+    /// only.
+    ///
+    /// A preprocessor's stdout is the JSON book stream mdbook reads back, so
+    /// `println!`/`eprintln!` must never be used for diagnostics - use the
+    /// `log` crate's `debug!`/`warn!`/`error!` macros instead, after calling
+    /// this once from `main`. Safe to call more than once; later calls are a
+    /// no-op.
+    pub fn init_logger() {
+        let _ =
+            env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn"))
+                .target(env_logger::Target::Stderr)
+                .try_init();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_context_and_book_from_stdin_shaped_json() {
+        let input = r##"[
+            {
+                "root": "/path/to/book",
+                "config": { "book": { "src": "src" } },
+                "renderer": "html",
+                "mdbook_version": "0.4.21"
+            },
+            { "sections": [] }
+        ]"##;
+
+        let (ctx, book) = CmdPreprocessor::parse_input(input.as_bytes()).unwrap();
+        assert_eq!(ctx.renderer, "html");
+        assert!(book.sections.is_empty());
+    }
+
+    #[test]
+    fn init_logger_is_idempotent() {
+        // a preprocessor's main() calls this unconditionally; a second call
+        // (e.g. from another test in the same binary) must not panic.
+        CmdPreprocessor::init_logger();
+        CmdPreprocessor::init_logger();
+    }
+
+    #[test]
+    fn emit_writes_the_book_as_json() {
+        let book = Book::new();
+        let mut out = Vec::new();
+        CmdPreprocessor::emit(&mut out, &book).unwrap();
+        let roundtripped: Book = serde_json::from_slice(&out).unwrap();
+        assert!(roundtripped.sections.is_empty());
+    }
+}
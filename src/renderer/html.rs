@@ -0,0 +1,83 @@
+use std::fmt::Write;
+
+use crate::book::{Book, BookItem, Chapter};
+
+/// Render a chapter's frontmatter `tags` as a row of HTML badges, one
+/// `<span class="tag">` per tag. Returns an empty string for chapters with
+/// no tags, so callers can splice this directly into a page template without
+/// checking first.
+pub fn tag_badges_html(chapter: &Chapter) -> String {
+    chapter
+        .frontmatter
+        .tags
+        .iter()
+        .fold(String::new(), |mut html, tag| {
+            let _ = write!(html, "<span class=\"tag\">{}</span>", html_escape(tag));
+            html
+        })
+}
+
+fn html_escape(raw: &str) -> String {
+    raw.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// The chapters the HTML renderer will actually write pages for.
+///
+/// Chapters marked `draft: true` in their frontmatter are excluded here,
+/// not earlier in the pipeline: preprocessors still see every chapter (a
+/// preprocessor may want to inspect or even un-draft a chapter), it's only
+/// the final render step that hides drafts from readers.
+pub fn visible_chapters(book: &Book) -> Vec<&Chapter> {
+    let mut chapters = Vec::new();
+    book.for_each(|item| {
+        if let BookItem::Chapter(chapter) = item {
+            if !chapter.frontmatter.draft {
+                chapters.push(chapter.as_ref());
+            }
+        }
+    });
+    chapters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::book::Chapter;
+
+    #[test]
+    fn drafts_are_excluded_from_visible_chapters() {
+        let mut draft = Chapter::new("hidden", "");
+        draft.frontmatter.draft = true;
+        let published = Chapter::new("shown", "");
+
+        let mut book = Book::new();
+        book.sections = vec![
+            BookItem::Chapter(Box::new(draft)),
+            BookItem::Chapter(Box::new(published)),
+        ];
+
+        let visible = visible_chapters(&book);
+        assert_eq!(visible.len(), 1);
+        assert_eq!(visible[0].name, "shown");
+    }
+
+    #[test]
+    fn tag_badges_are_escaped_and_joined() {
+        let mut chapter = Chapter::new("chapter", "");
+        chapter.frontmatter.tags = vec!["rust".to_string(), "<cli>".to_string()];
+
+        assert_eq!(
+            tag_badges_html(&chapter),
+            "<span class=\"tag\">rust</span><span class=\"tag\">&lt;cli&gt;</span>"
+        );
+    }
+
+    #[test]
+    fn tag_badges_are_empty_for_untagged_chapters() {
+        let chapter = Chapter::new("chapter", "");
+        assert_eq!(tag_badges_html(&chapter), "");
+    }
+}
@@ -0,0 +1,4 @@
+//! Output renderers. Only the pieces of the HTML renderer relevant to
+//! frontmatter (draft filtering, tag badges) live here.
+
+pub mod html;